@@ -0,0 +1,210 @@
+//! All-Pairs Shortest Paths
+//!
+//! Answers shortest-path queries between any pair of nodes by running
+//! [`dijkstra`] from every vertex and keeping each source's result around
+//! for reconstruction, plus a dense Floyd-Warshall backend that is simpler
+//! and faster when the graph is near-complete.
+//!
+//! Time Complexity: O(V * (V + E) log V) for the per-source backend,
+//! O(V^3) for the Floyd-Warshall backend.
+
+use crate::dijkstra::{dijkstra, DijkstraResult, Graph, Weight};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Run Dijkstra from every vertex in `graph`, keyed by source.
+pub fn all_pairs_shortest_paths<T, W>(graph: &Graph<T, W>) -> HashMap<T, DijkstraResult<T, W>>
+where
+    T: Eq + Hash + Clone,
+    W: Weight,
+{
+    graph
+        .keys()
+        .map(|source| (source.clone(), dijkstra(graph, source)))
+        .collect()
+}
+
+/// Dense distance matrix for `graph`, alongside the row/column index
+/// assigned to each node. Unreachable pairs are `f64::INFINITY`.
+pub fn distance_matrix<T, W>(graph: &Graph<T, W>) -> (Vec<Vec<f64>>, HashMap<T, usize>)
+where
+    T: Eq + Hash + Clone,
+    W: Weight,
+{
+    let nodes: Vec<T> = graph.keys().cloned().collect();
+    let index: HashMap<T, usize> = nodes.iter().cloned().enumerate().map(|(i, n)| (n, i)).collect();
+    let n = nodes.len();
+    let mut matrix = vec![vec![f64::INFINITY; n]; n];
+
+    for (i, source) in nodes.iter().enumerate() {
+        let result = dijkstra(graph, source);
+        for (j, target) in nodes.iter().enumerate() {
+            if let Some(Some(distance)) = result.distances.get(target) {
+                matrix[i][j] = distance.to_f64();
+            }
+        }
+    }
+
+    (matrix, index)
+}
+
+/// Reconstruct the path from `start` to `end` using the per-source results
+/// from [`all_pairs_shortest_paths`], without rerunning Dijkstra.
+///
+/// Returns `None` if `start` was not a source in `results`, or `end` is
+/// unreachable from it.
+pub fn reconstruct<T, W>(results: &HashMap<T, DijkstraResult<T, W>>, start: &T, end: &T) -> Option<Vec<T>>
+where
+    T: Eq + Hash + Clone,
+    W: Weight,
+{
+    let result = results.get(start)?;
+    match result.distances.get(end) {
+        Some(Some(_)) => {}
+        _ => return None,
+    }
+
+    let mut path = Vec::new();
+    let mut current = Some(end.clone());
+    while let Some(node) = current {
+        path.push(node.clone());
+        current = result.predecessors.get(&node)?.clone();
+    }
+    path.reverse();
+
+    Some(path)
+}
+
+/// Floyd-Warshall path-reconstruction matrix: `next[i][j]` is the index of
+/// the node after `i` on the shortest path to `j`, or `None` if there is
+/// none.
+type NextMatrix = Vec<Vec<Option<usize>>>;
+
+/// All-pairs shortest distances via the Floyd-Warshall triple loop.
+///
+/// Works directly on a dense `V x V` matrix rather than paying per-vertex
+/// heap overhead, so it is simpler and faster than [`distance_matrix`] when
+/// the graph is near-complete. Returns the distance matrix, a `next` matrix
+/// for path reconstruction (`next[i][j]` is the index of the node after `i`
+/// on the shortest path to `j`, or `None` if there is none), and the nodes
+/// in index order.
+pub fn all_pairs_floyd_warshall<T, W>(graph: &Graph<T, W>) -> (Vec<Vec<f64>>, NextMatrix, Vec<T>)
+where
+    T: Eq + Hash + Clone,
+    W: Weight,
+{
+    let nodes: Vec<T> = graph.keys().cloned().collect();
+    let index: HashMap<T, usize> = nodes.iter().cloned().enumerate().map(|(i, n)| (n, i)).collect();
+    let n = nodes.len();
+
+    let mut dist = vec![vec![f64::INFINITY; n]; n];
+    let mut next: NextMatrix = vec![vec![None; n]; n];
+
+    for (i, row) in dist.iter_mut().enumerate() {
+        row[i] = 0.0;
+    }
+    for (u, edges) in graph {
+        let i = index[u];
+        for (v, weight) in edges {
+            let j = index[v];
+            let w = weight.to_f64();
+            if w < dist[i][j] {
+                dist[i][j] = w;
+                next[i][j] = Some(j);
+            }
+        }
+    }
+
+    for k in 0..n {
+        for i in 0..n {
+            for j in 0..n {
+                if dist[i][k] + dist[k][j] < dist[i][j] {
+                    dist[i][j] = dist[i][k] + dist[k][j];
+                    next[i][j] = next[i][k];
+                }
+            }
+        }
+    }
+
+    (dist, next, nodes)
+}
+
+/// Reconstruct a path from a Floyd-Warshall `next` matrix and its
+/// accompanying `nodes` index order (as returned by
+/// [`all_pairs_floyd_warshall`]).
+pub fn floyd_warshall_path<T>(next: &[Vec<Option<usize>>], nodes: &[T], start: &T, end: &T) -> Option<Vec<T>>
+where
+    T: Eq + Hash + Clone,
+{
+    let mut i = nodes.iter().position(|n| n == start)?;
+    let j = nodes.iter().position(|n| n == end)?;
+
+    // The triple loop never populates next[i][i] (no negative self-loop
+    // ever beats dist[i][i] = 0.0), even though the trivial path exists.
+    if i == j {
+        return Some(vec![nodes[i].clone()]);
+    }
+    next[i][j]?;
+
+    let mut path = vec![nodes[i].clone()];
+    while i != j {
+        i = next[i][j]?;
+        path.push(nodes[i].clone());
+    }
+
+    Some(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_all_pairs_shortest_paths_and_reconstruct() {
+        let mut graph: Graph<&str, i32> = HashMap::new();
+        graph.insert("A", vec![("B", 1), ("C", 4)]);
+        graph.insert("B", vec![("C", 2)]);
+        graph.insert("C", vec![]);
+
+        let results = all_pairs_shortest_paths(&graph);
+
+        assert_eq!(results[&"A"].distances[&"C"], Some(3));
+        assert_eq!(reconstruct(&results, &"A", &"C"), Some(vec!["A", "B", "C"]));
+        assert_eq!(reconstruct(&results, &"C", &"A"), None);
+    }
+
+    #[test]
+    fn test_distance_matrix() {
+        let mut graph: Graph<&str, i32> = HashMap::new();
+        graph.insert("A", vec![("B", 1)]);
+        graph.insert("B", vec![]);
+
+        let (matrix, index) = distance_matrix(&graph);
+
+        assert_eq!(matrix[index[&"A"]][index[&"A"]], 0.0);
+        assert_eq!(matrix[index[&"A"]][index[&"B"]], 1.0);
+        assert_eq!(matrix[index[&"B"]][index[&"A"]], f64::INFINITY);
+    }
+
+    #[test]
+    fn test_floyd_warshall_matches_dijkstra() {
+        let mut graph: Graph<&str, i32> = HashMap::new();
+        graph.insert("A", vec![("B", 1), ("C", 4)]);
+        graph.insert("B", vec![("C", 2)]);
+        graph.insert("C", vec![]);
+
+        let (dist, next, nodes) = all_pairs_floyd_warshall(&graph);
+        let index_of = |n: &str| nodes.iter().position(|x| *x == n).unwrap();
+
+        assert_eq!(dist[index_of("A")][index_of("C")], 3.0);
+        assert_eq!(
+            floyd_warshall_path(&next, &nodes, &"A", &"C"),
+            Some(vec!["A", "B", "C"])
+        );
+        assert_eq!(floyd_warshall_path(&next, &nodes, &"C", &"A"), None);
+        assert_eq!(
+            floyd_warshall_path(&next, &nodes, &"A", &"A"),
+            Some(vec!["A"])
+        );
+    }
+}
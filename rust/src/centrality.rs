@@ -0,0 +1,207 @@
+//! Node Centrality
+//!
+//! Computes closeness and betweenness centrality by running the
+//! shortest-path machinery from [`crate::dijkstra`] from every source node.
+//!
+//! Time Complexity: O(V) Dijkstra runs, i.e. O(V * (V + E) log V) overall.
+
+use crate::dijkstra::{dijkstra, Graph, PQItem, Weight};
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::hash::Hash;
+
+/// Closeness centrality of every node in `graph`.
+///
+/// For each node `v`, sums the shortest-path distances to all nodes
+/// reachable from it and scores `closeness[v] = (reachable - 1) /
+/// sum_of_distances`, where `reachable` counts `v` itself plus every node it
+/// can reach. Isolated nodes score `0.0`.
+///
+/// When `normalized` is `true`, applies the Wasserman-Faust correction for
+/// disconnected graphs: `((reachable - 1) / (n - 1)) * ((reachable - 1) /
+/// sum_of_distances)`, where `n` is the total number of nodes in the graph.
+pub fn closeness_centrality<T, W>(graph: &Graph<T, W>, normalized: bool) -> HashMap<T, f64>
+where
+    T: Eq + Hash + Clone,
+    W: Weight,
+{
+    let n = graph.len();
+    let mut scores = HashMap::with_capacity(n);
+
+    for source in graph.keys() {
+        let result = dijkstra(graph, source);
+
+        let mut reachable = 0usize;
+        let mut sum_of_distances = 0.0f64;
+        for distance in result.distances.values().flatten() {
+            reachable += 1;
+            sum_of_distances += distance.to_f64();
+        }
+
+        let closeness = if reachable <= 1 || sum_of_distances == 0.0 {
+            0.0
+        } else if normalized {
+            let reached = (reachable - 1) as f64;
+            (reached / (n - 1) as f64) * (reached / sum_of_distances)
+        } else {
+            (reachable - 1) as f64 / sum_of_distances
+        };
+
+        scores.insert(source.clone(), closeness);
+    }
+
+    scores
+}
+
+/// Betweenness centrality of every node in `graph`, via Brandes' algorithm.
+///
+/// From each source `s`, runs Dijkstra while recording the number of
+/// shortest paths `sigma` from `s` to each node and its shortest-path-DAG
+/// predecessors, then back-propagates dependencies from the nodes farthest
+/// from `s` to accumulate each node's contribution to every pair's shortest
+/// paths.
+///
+/// Pass `undirected: true` to halve the result, since an undirected graph
+/// counts every pair's shortest path once from each endpoint.
+pub fn betweenness_centrality<T, W>(graph: &Graph<T, W>, undirected: bool) -> HashMap<T, f64>
+where
+    T: Eq + Hash + Clone,
+    W: Weight,
+{
+    let mut betweenness: HashMap<T, f64> = graph.keys().map(|n| (n.clone(), 0.0)).collect();
+
+    for s in graph.keys() {
+        let mut stack: Vec<T> = Vec::new();
+        let mut predecessors: HashMap<T, Vec<T>> =
+            graph.keys().map(|n| (n.clone(), Vec::new())).collect();
+        let mut sigma: HashMap<T, f64> = graph.keys().map(|n| (n.clone(), 0.0)).collect();
+        let mut dist: HashMap<T, Option<W>> = graph.keys().map(|n| (n.clone(), None)).collect();
+        let mut visited: HashSet<T> = HashSet::new();
+
+        sigma.insert(s.clone(), 1.0);
+        dist.insert(s.clone(), Some(W::ZERO));
+
+        let mut pq = BinaryHeap::new();
+        pq.push(PQItem {
+            node: s.clone(),
+            distance: W::ZERO,
+        });
+
+        while let Some(PQItem { node: v, distance: d }) = pq.pop() {
+            if visited.contains(&v) {
+                continue;
+            }
+            visited.insert(v.clone());
+
+            if dist[&v].is_some_and(|best| d > best) {
+                continue;
+            }
+            stack.push(v.clone());
+
+            if let Some(neighbors) = graph.get(&v) {
+                for (w, weight) in neighbors {
+                    let new_dist = d + *weight;
+
+                    match dist[w] {
+                        Some(best) if new_dist < best => {
+                            dist.insert(w.clone(), Some(new_dist));
+                            sigma.insert(w.clone(), sigma[&v]);
+                            predecessors.insert(w.clone(), vec![v.clone()]);
+                            pq.push(PQItem {
+                                node: w.clone(),
+                                distance: new_dist,
+                            });
+                        }
+                        Some(best) if new_dist == best => {
+                            *sigma.get_mut(w).unwrap() += sigma[&v];
+                            predecessors.get_mut(w).unwrap().push(v.clone());
+                        }
+                        Some(_) => {}
+                        None => {
+                            dist.insert(w.clone(), Some(new_dist));
+                            sigma.insert(w.clone(), sigma[&v]);
+                            predecessors.insert(w.clone(), vec![v.clone()]);
+                            pq.push(PQItem {
+                                node: w.clone(),
+                                distance: new_dist,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut delta: HashMap<T, f64> = graph.keys().map(|n| (n.clone(), 0.0)).collect();
+        while let Some(w) = stack.pop() {
+            for v in &predecessors[&w] {
+                let contribution = (sigma[v] / sigma[&w]) * (1.0 + delta[&w]);
+                *delta.get_mut(v).unwrap() += contribution;
+            }
+            if w != *s {
+                *betweenness.get_mut(&w).unwrap() += delta[&w];
+            }
+        }
+    }
+
+    if undirected {
+        for score in betweenness.values_mut() {
+            *score /= 2.0;
+        }
+    }
+
+    betweenness
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap as StdHashMap;
+
+    #[test]
+    fn test_closeness_centrality_path_graph() {
+        let mut graph: Graph<&str, i32> = StdHashMap::new();
+        graph.insert("A", vec![("B", 1)]);
+        graph.insert("B", vec![("C", 1)]);
+        graph.insert("C", vec![]);
+
+        let scores = closeness_centrality(&graph, false);
+
+        // B reaches A? no, edges are directed A->B->C, so from B only C is
+        // reachable (distance 1): closeness = (2 - 1) / 1 = 1.0
+        assert_eq!(scores[&"B"], 1.0);
+        // From A, reachable = {A, B, C}, sum = 1 + 2 = 3: (3 - 1) / 3
+        assert!((scores[&"A"] - 2.0 / 3.0).abs() < 1e-9);
+        // C has no outgoing edges, so it is isolated from its own perspective
+        assert_eq!(scores[&"C"], 0.0);
+    }
+
+    #[test]
+    fn test_betweenness_centrality_complete_graph() {
+        // A, B, C all directly connected: no node lies strictly between any
+        // other pair, so nothing ever needs to route through a third node.
+        let mut graph: Graph<&str, i32> = StdHashMap::new();
+        graph.insert("A", vec![("B", 1), ("C", 1)]);
+        graph.insert("B", vec![("A", 1), ("C", 1)]);
+        graph.insert("C", vec![("A", 1), ("B", 1)]);
+
+        let scores = betweenness_centrality(&graph, true);
+
+        assert_eq!(scores[&"A"], 0.0);
+        assert_eq!(scores[&"B"], 0.0);
+        assert_eq!(scores[&"C"], 0.0);
+    }
+
+    #[test]
+    fn test_betweenness_centrality_path_graph() {
+        // A - B - C (undirected): B sits on the only shortest path A-C.
+        let mut graph: Graph<&str, i32> = StdHashMap::new();
+        graph.insert("A", vec![("B", 1)]);
+        graph.insert("B", vec![("A", 1), ("C", 1)]);
+        graph.insert("C", vec![("B", 1)]);
+
+        let scores = betweenness_centrality(&graph, true);
+
+        assert_eq!(scores[&"A"], 0.0);
+        assert_eq!(scores[&"C"], 0.0);
+        assert_eq!(scores[&"B"], 1.0);
+    }
+}
@@ -3,55 +3,100 @@
 //! Finds the shortest path from a source node to all other nodes in a weighted graph
 //! with non-negative edge weights.
 //!
+//! Edge weights are generic over any type implementing [`Weight`], so the
+//! algorithm works with integer weights (exact comparisons, no silent `NaN`
+//! misbehavior) as well as any other ordered, additive quantity.
+//!
 //! Time Complexity: O((V + E) log V) with binary heap
 //! Space Complexity: O(V)
 
 use std::cmp::Ordering;
 use std::collections::{BinaryHeap, HashMap, HashSet};
 use std::hash::Hash;
+use std::ops::Add;
+
+/// An edge weight: copyable, totally ordered, additive, and with a
+/// well-defined zero (the distance from a node to itself).
+///
+/// Implemented for the built-in integer types. `f64` is deliberately not
+/// implemented: it is only partially ordered (`NaN`), which previously made
+/// the priority queue's ordering silently fall back to `Ordering::Equal`.
+pub trait Weight: Copy + Ord + Add<Output = Self> {
+    /// The additive identity.
+    const ZERO: Self;
+
+    /// Lossy conversion to `f64`, for metrics that need a fractional
+    /// quantity (e.g. closeness centrality, dense distance matrices).
+    /// `f64: From<W>` isn't implementable for every integer width the
+    /// library supports (`i64`, `u64`, `isize`, `usize`, ... lose
+    /// precision), so this is a crate-controlled `as` cast instead.
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_weight {
+    ($($t:ty),*) => {
+        $(impl Weight for $t {
+            const ZERO: $t = 0;
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+        })*
+    };
+}
+
+impl_weight!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
 
 /// Graph represented as an adjacency list
-pub type Graph<'a, T> = HashMap<T, Vec<(T, f64)>>;
+pub type Graph<'a, T, W> = HashMap<T, Vec<(T, W)>>;
 
 /// Result of Dijkstra's algorithm
+///
+/// `distances[node]` is `None` when `node` is unreachable from the source,
+/// rather than relying on a float-infinity sentinel.
 #[derive(Debug, Clone)]
-pub struct DijkstraResult<T: Clone> {
-    pub distances: HashMap<T, f64>,
+pub struct DijkstraResult<T: Clone, W> {
+    pub distances: HashMap<T, Option<W>>,
     pub predecessors: HashMap<T, Option<T>>,
 }
 
 /// Path result between two nodes
 #[derive(Debug, Clone)]
-pub struct PathResult<T: Clone> {
-    pub distance: f64,
+pub struct PathResult<T: Clone, W> {
+    pub distance: W,
     pub path: Vec<T>,
 }
 
-/// Priority queue item for Dijkstra's algorithm
+/// A min-heap entry ordering by `distance` alone (reversed, so a
+/// `BinaryHeap` pops the smallest first), carrying an arbitrary payload in
+/// `node`. Shared by every algorithm in this crate that needs a priority
+/// queue over a distance-like quantity: plain Dijkstra keys it by the
+/// frontier node, [`crate::yen`] keys it by a whole candidate path, and
+/// [`crate::centrality`] keys it by the node being relaxed.
 #[derive(Debug, Clone)]
-struct PQItem<T> {
-    node: T,
-    distance: f64,
+pub(crate) struct PQItem<T, W> {
+    pub(crate) node: T,
+    pub(crate) distance: W,
 }
 
-impl<T> PartialEq for PQItem<T> {
+impl<T, W: PartialEq> PartialEq for PQItem<T, W> {
     fn eq(&self, other: &Self) -> bool {
         self.distance == other.distance
     }
 }
 
-impl<T> Eq for PQItem<T> {}
+impl<T, W: Eq> Eq for PQItem<T, W> {}
 
-impl<T> PartialOrd for PQItem<T> {
+impl<T, W: Ord> PartialOrd for PQItem<T, W> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
 }
 
-impl<T> Ord for PQItem<T> {
+impl<T, W: Ord> Ord for PQItem<T, W> {
     fn cmp(&self, other: &Self) -> Ordering {
         // Reverse ordering for min-heap
-        other.distance.partial_cmp(&self.distance).unwrap_or(Ordering::Equal)
+        other.distance.cmp(&self.distance)
     }
 }
 
@@ -72,35 +117,36 @@ impl<T> Ord for PQItem<T> {
 /// use graph_algorithms::dijkstra::{dijkstra, Graph};
 /// use std::collections::HashMap;
 ///
-/// let mut graph: Graph<&str> = HashMap::new();
-/// graph.insert("A", vec![("B", 4.0), ("C", 2.0)]);
+/// let mut graph: Graph<&str, i32> = HashMap::new();
+/// graph.insert("A", vec![("B", 4), ("C", 2)]);
 /// graph.insert("B", vec![]);
 /// graph.insert("C", vec![]);
 ///
 /// let result = dijkstra(&graph, &"A");
-/// assert_eq!(result.distances[&"A"], 0.0);
-/// assert_eq!(result.distances[&"B"], 4.0);
+/// assert_eq!(result.distances[&"A"], Some(0));
+/// assert_eq!(result.distances[&"B"], Some(4));
 /// ```
-pub fn dijkstra<T>(graph: &Graph<T>, start: &T) -> DijkstraResult<T>
+pub fn dijkstra<T, W>(graph: &Graph<T, W>, start: &T) -> DijkstraResult<T, W>
 where
     T: Eq + Hash + Clone,
+    W: Weight,
 {
-    let mut distances: HashMap<T, f64> = HashMap::new();
+    let mut distances: HashMap<T, Option<W>> = HashMap::new();
     let mut predecessors: HashMap<T, Option<T>> = HashMap::new();
     let mut visited: HashSet<T> = HashSet::new();
 
     // Initialize distances
     for node in graph.keys() {
-        distances.insert(node.clone(), f64::INFINITY);
+        distances.insert(node.clone(), None);
         predecessors.insert(node.clone(), None);
     }
-    distances.insert(start.clone(), 0.0);
+    distances.insert(start.clone(), Some(W::ZERO));
 
     // Priority queue
     let mut pq = BinaryHeap::new();
     pq.push(PQItem {
         node: start.clone(),
-        distance: 0.0,
+        distance: W::ZERO,
     });
 
     while let Some(PQItem { node: current, distance: current_distance }) = pq.pop() {
@@ -111,18 +157,22 @@ where
         visited.insert(current.clone());
 
         // Skip if we found a better path already
-        if current_distance > distances[&current] {
+        if distances[&current].is_some_and(|best| current_distance > best) {
             continue;
         }
 
         // Check all neighbors
         if let Some(neighbors) = graph.get(&current) {
             for (neighbor, weight) in neighbors {
-                let distance = current_distance + weight;
+                let distance = current_distance + *weight;
 
                 // If we found a shorter path, update it
-                if distance < distances[neighbor] {
-                    distances.insert(neighbor.clone(), distance);
+                let is_shorter = match distances[neighbor] {
+                    Some(best) => distance < best,
+                    None => true,
+                };
+                if is_shorter {
+                    distances.insert(neighbor.clone(), Some(distance));
                     predecessors.insert(neighbor.clone(), Some(current.clone()));
                     pq.push(PQItem {
                         node: neighbor.clone(),
@@ -150,16 +200,18 @@ where
 /// # Returns
 ///
 /// `Result` containing `PathResult` or error if no path exists
-pub fn dijkstra_path<T>(graph: &Graph<T>, start: &T, end: &T) -> Result<PathResult<T>, String>
+pub fn dijkstra_path<T, W>(graph: &Graph<T, W>, start: &T, end: &T) -> Result<PathResult<T, W>, String>
 where
-    T: Eq + Hash + Clone,
+    T: Eq + Hash + Clone + std::fmt::Debug,
+    W: Weight,
 {
     let result = dijkstra(graph, start);
 
     // Check if path exists
-    if result.distances[end].is_infinite() {
-        return Err(format!("No path exists from {:?} to {:?}", start, end));
-    }
+    let distance = match result.distances[end] {
+        Some(distance) => distance,
+        None => return Err(format!("No path exists from {:?} to {:?}", start, end)),
+    };
 
     // Reconstruct path
     let mut path = Vec::new();
@@ -172,10 +224,202 @@ where
 
     path.reverse();
 
-    Ok(PathResult {
-        distance: result.distances[end],
-        path,
-    })
+    Ok(PathResult { distance, path })
+}
+
+/// Result of [`dijkstra_all_predecessors`]: like `DijkstraResult`, but
+/// `predecessors` keeps every predecessor that achieves the minimum
+/// distance rather than just one, so the shortest-path DAG can be walked to
+/// enumerate every tied shortest path.
+#[derive(Debug, Clone)]
+pub struct AllPredecessorsResult<T: Clone, W> {
+    pub distances: HashMap<T, Option<W>>,
+    pub predecessors: HashMap<T, Vec<T>>,
+}
+
+/// Run Dijkstra's algorithm from a source node, recording every predecessor
+/// that achieves the minimum distance to each node (not just one).
+///
+/// A strictly shorter edge resets a node's predecessor list; an
+/// equal-distance edge appends to it. Pass the result to
+/// [`all_shortest_paths`] to enumerate every minimum-cost path between two
+/// nodes.
+pub fn dijkstra_all_predecessors<T, W>(graph: &Graph<T, W>, start: &T) -> AllPredecessorsResult<T, W>
+where
+    T: Eq + Hash + Clone,
+    W: Weight,
+{
+    let mut distances: HashMap<T, Option<W>> = HashMap::new();
+    let mut predecessors: HashMap<T, Vec<T>> = HashMap::new();
+    let mut visited: HashSet<T> = HashSet::new();
+
+    for node in graph.keys() {
+        distances.insert(node.clone(), None);
+        predecessors.insert(node.clone(), Vec::new());
+    }
+    distances.insert(start.clone(), Some(W::ZERO));
+
+    let mut pq = BinaryHeap::new();
+    pq.push(PQItem {
+        node: start.clone(),
+        distance: W::ZERO,
+    });
+
+    while let Some(PQItem { node: current, distance: current_distance }) = pq.pop() {
+        if visited.contains(&current) {
+            continue;
+        }
+        visited.insert(current.clone());
+
+        if distances[&current].is_some_and(|best| current_distance > best) {
+            continue;
+        }
+
+        if let Some(neighbors) = graph.get(&current) {
+            for (neighbor, weight) in neighbors {
+                let distance = current_distance + *weight;
+
+                match distances[neighbor] {
+                    Some(best) if distance < best => {
+                        distances.insert(neighbor.clone(), Some(distance));
+                        predecessors.insert(neighbor.clone(), vec![current.clone()]);
+                        pq.push(PQItem {
+                            node: neighbor.clone(),
+                            distance,
+                        });
+                    }
+                    Some(best) if distance == best => {
+                        predecessors.get_mut(neighbor).unwrap().push(current.clone());
+                    }
+                    Some(_) => {}
+                    None => {
+                        distances.insert(neighbor.clone(), Some(distance));
+                        predecessors.insert(neighbor.clone(), vec![current.clone()]);
+                        pq.push(PQItem {
+                            node: neighbor.clone(),
+                            distance,
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    AllPredecessorsResult {
+        distances,
+        predecessors,
+    }
+}
+
+/// Enumerate every minimum-cost path from `start` to `end` by walking the
+/// shortest-path DAG recorded in `result` backwards from `end`.
+///
+/// Returns an empty `Vec` if `end` is unreachable from `start`.
+///
+/// The number of tied shortest paths can be exponential in the size of the
+/// graph (e.g. an N x N unit-weight grid has millions of shortest paths
+/// between opposite corners), and this function both recurses and allocates
+/// proportionally to that count. Prefer [`dijkstra_path`] when only one
+/// shortest path is needed, and only reach for this on graphs where ties are
+/// known to be rare.
+pub fn all_shortest_paths<T, W>(result: &AllPredecessorsResult<T, W>, start: &T, end: &T) -> Vec<Vec<T>>
+where
+    T: Eq + Hash + Clone,
+{
+    if end == start {
+        return vec![vec![start.clone()]];
+    }
+
+    let mut paths = Vec::new();
+    if let Some(preds) = result.predecessors.get(end) {
+        for pred in preds {
+            for mut path in all_shortest_paths(result, start, pred) {
+                path.push(end.clone());
+                paths.push(path);
+            }
+        }
+    }
+    paths
+}
+
+/// Run Dijkstra's algorithm over an implicit graph, generating neighbors on
+/// demand instead of requiring a materialized [`Graph`].
+///
+/// `successors` produces a node's outgoing edges only when that node is
+/// popped from the frontier, so the full state space never needs to exist
+/// in memory — useful for large or effectively infinite graphs such as
+/// puzzle states or procedurally generated maps. The search stops as soon
+/// as `success` accepts a popped node.
+///
+/// # Returns
+///
+/// The cost and path to the first node accepted by `success`, or `None` if
+/// no such node is reachable.
+pub fn dijkstra_fn<T, W>(
+    start: T,
+    mut successors: impl FnMut(&T) -> Vec<(T, W)>,
+    mut success: impl FnMut(&T) -> bool,
+) -> Option<PathResult<T, W>>
+where
+    T: Eq + Hash + Clone,
+    W: Weight,
+{
+    let mut distances: HashMap<T, W> = HashMap::new();
+    let mut predecessors: HashMap<T, Option<T>> = HashMap::new();
+    let mut visited: HashSet<T> = HashSet::new();
+
+    distances.insert(start.clone(), W::ZERO);
+    predecessors.insert(start.clone(), None);
+
+    let mut pq = BinaryHeap::new();
+    pq.push(PQItem {
+        node: start.clone(),
+        distance: W::ZERO,
+    });
+
+    while let Some(PQItem { node: current, distance: current_distance }) = pq.pop() {
+        if visited.contains(&current) {
+            continue;
+        }
+        visited.insert(current.clone());
+
+        if current_distance > distances[&current] {
+            continue;
+        }
+
+        if success(&current) {
+            let mut path = Vec::new();
+            let mut node = Some(current.clone());
+            while let Some(n) = node {
+                path.push(n.clone());
+                node = predecessors[&n].clone();
+            }
+            path.reverse();
+            return Some(PathResult {
+                distance: current_distance,
+                path,
+            });
+        }
+
+        for (neighbor, weight) in successors(&current) {
+            let distance = current_distance + weight;
+
+            let is_shorter = match distances.get(&neighbor) {
+                Some(&best) => distance < best,
+                None => true,
+            };
+            if is_shorter {
+                distances.insert(neighbor.clone(), distance);
+                predecessors.insert(neighbor.clone(), Some(current.clone()));
+                pq.push(PQItem {
+                    node: neighbor,
+                    distance,
+                });
+            }
+        }
+    }
+
+    None
 }
 
 #[cfg(test)]
@@ -184,38 +428,38 @@ mod tests {
 
     #[test]
     fn test_simple_graph() {
-        let mut graph: Graph<&str> = HashMap::new();
-        graph.insert("A", vec![("B", 4.0), ("C", 2.0)]);
-        graph.insert("B", vec![("C", 1.0), ("D", 5.0)]);
-        graph.insert("C", vec![("D", 8.0)]);
+        let mut graph: Graph<&str, i32> = HashMap::new();
+        graph.insert("A", vec![("B", 4), ("C", 2)]);
+        graph.insert("B", vec![("C", 1), ("D", 5)]);
+        graph.insert("C", vec![("D", 8)]);
         graph.insert("D", vec![]);
 
         let result = dijkstra(&graph, &"A");
 
-        assert_eq!(result.distances[&"A"], 0.0);
-        assert_eq!(result.distances[&"B"], 4.0);
-        assert_eq!(result.distances[&"C"], 2.0);
-        assert_eq!(result.distances[&"D"], 9.0);
+        assert_eq!(result.distances[&"A"], Some(0));
+        assert_eq!(result.distances[&"B"], Some(4));
+        assert_eq!(result.distances[&"C"], Some(2));
+        assert_eq!(result.distances[&"D"], Some(9));
     }
 
     #[test]
     fn test_path_finding() {
-        let mut graph: Graph<&str> = HashMap::new();
-        graph.insert("A", vec![("B", 1.0), ("C", 4.0)]);
-        graph.insert("B", vec![("C", 2.0), ("D", 5.0)]);
-        graph.insert("C", vec![("D", 1.0)]);
+        let mut graph: Graph<&str, i32> = HashMap::new();
+        graph.insert("A", vec![("B", 1), ("C", 4)]);
+        graph.insert("B", vec![("C", 2), ("D", 5)]);
+        graph.insert("C", vec![("D", 1)]);
         graph.insert("D", vec![]);
 
         let result = dijkstra_path(&graph, &"A", &"D").unwrap();
 
-        assert_eq!(result.distance, 4.0);
+        assert_eq!(result.distance, 4);
         assert_eq!(result.path, vec!["A", "B", "C", "D"]);
     }
 
     #[test]
     fn test_no_path() {
-        let mut graph: Graph<&str> = HashMap::new();
-        graph.insert("A", vec![("B", 1.0)]);
+        let mut graph: Graph<&str, i32> = HashMap::new();
+        graph.insert("A", vec![("B", 1)]);
         graph.insert("B", vec![]);
         graph.insert("C", vec![]);
 
@@ -223,4 +467,54 @@ mod tests {
 
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_all_shortest_paths_with_ties() {
+        // Two equally short routes from A to D: A-B-D and A-C-D.
+        let mut graph: Graph<&str, i32> = HashMap::new();
+        graph.insert("A", vec![("B", 1), ("C", 1)]);
+        graph.insert("B", vec![("D", 1)]);
+        graph.insert("C", vec![("D", 1)]);
+        graph.insert("D", vec![]);
+
+        let result = dijkstra_all_predecessors(&graph, &"A");
+        assert_eq!(result.distances[&"D"], Some(2));
+
+        let mut paths = all_shortest_paths(&result, &"A", &"D");
+        paths.sort();
+
+        assert_eq!(
+            paths,
+            vec![vec!["A", "B", "D"], vec!["A", "C", "D"]]
+        );
+    }
+
+    #[test]
+    fn test_all_shortest_paths_no_path() {
+        let mut graph: Graph<&str, i32> = HashMap::new();
+        graph.insert("A", vec![]);
+        graph.insert("B", vec![]);
+
+        let result = dijkstra_all_predecessors(&graph, &"A");
+
+        assert!(all_shortest_paths(&result, &"A", &"B").is_empty());
+    }
+
+    #[test]
+    fn test_dijkstra_fn_lazy_graph() {
+        // Implicit graph: each node n connects to n + 1 (cost 2) and n + 3
+        // (cost 3). Reaching 6 cheapest via two +3 steps (cost 6) rather
+        // than any combination involving a +1 step (cost >= 9).
+        let result = dijkstra_fn(0i32, |&n| vec![(n + 1, 2), (n + 3, 3)], |&n| n == 6).unwrap();
+
+        assert_eq!(result.distance, 6);
+        assert_eq!(result.path, vec![0, 3, 6]);
+    }
+
+    #[test]
+    fn test_dijkstra_fn_unreachable() {
+        let result = dijkstra_fn(0i32, |_| Vec::<(i32, i32)>::new(), |&n| n == 5);
+
+        assert!(result.is_none());
+    }
 }
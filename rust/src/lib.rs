@@ -8,6 +8,9 @@
 //! - **A* Search**: Heuristic-based pathfinding
 //! - **Bellman-Ford**: Shortest path with negative weights, cycle detection
 //! - **BFS**: Shortest path in unweighted graphs
+//! - **Yen's Algorithm**: K shortest loopless paths between two nodes
+//! - **Centrality**: Closeness and Brandes betweenness centrality
+//! - **All-Pairs Shortest Paths**: Per-source Dijkstra or dense Floyd-Warshall
 //!
 //! # Example
 //!
@@ -15,17 +18,20 @@
 //! use graph_algorithms::dijkstra::{Graph, dijkstra_path};
 //! use std::collections::HashMap;
 //!
-//! let mut graph = HashMap::new();
-//! graph.insert("A", vec![("B", 4.0), ("C", 2.0)]);
-//! graph.insert("B", vec![("D", 5.0)]);
-//! graph.insert("C", vec![("D", 1.0)]);
+//! let mut graph: Graph<&str, i32> = HashMap::new();
+//! graph.insert("A", vec![("B", 4), ("C", 2)]);
+//! graph.insert("B", vec![("D", 5)]);
+//! graph.insert("C", vec![("D", 1)]);
 //! graph.insert("D", vec![]);
 //!
-//! let (distance, path) = dijkstra_path(&graph, "A", "D").unwrap();
-//! assert_eq!(distance, 3.0);
+//! let result = dijkstra_path(&graph, &"A", &"D").unwrap();
+//! assert_eq!(result.distance, 3);
 //! ```
 
 pub mod dijkstra;
 pub mod astar;
 pub mod bellman_ford;
 pub mod bfs;
+pub mod yen;
+pub mod centrality;
+pub mod all_pairs;
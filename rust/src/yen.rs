@@ -0,0 +1,201 @@
+//! Yen's K-Shortest Loopless Paths
+//!
+//! Finds the `K` shortest loopless (simple) paths between two nodes, built
+//! on top of the existing [`dijkstra_path`]. Each iteration picks a spur
+//! node along the previous shortest path, removes the edges and nodes that
+//! would only reproduce paths already found, and reruns Dijkstra from the
+//! spur node to assemble a new candidate.
+//!
+//! Reference: J. Y. Yen, "Finding the K Shortest Loopless Paths in a
+//! Network", Management Science, 1971.
+
+use crate::dijkstra::{dijkstra_path, Graph, PQItem, PathResult, Weight};
+use std::collections::BinaryHeap;
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// Sum the edge weights along `path` as they appear in `graph`.
+fn path_distance<T, W>(graph: &Graph<T, W>, path: &[T]) -> W
+where
+    T: Eq + Hash + Clone,
+    W: Weight,
+{
+    let mut total = W::ZERO;
+    for window in path.windows(2) {
+        let (from, to) = (&window[0], &window[1]);
+        if let Some(edges) = graph.get(from) {
+            if let Some((_, weight)) = edges.iter().find(|(node, _)| node == to) {
+                total = total + *weight;
+            }
+        }
+    }
+    total
+}
+
+/// Find the `k` shortest loopless paths from `start` to `end`.
+///
+/// # Arguments
+///
+/// * `graph` - Adjacency list representation of the graph
+/// * `start` - Starting node
+/// * `end` - Target node
+/// * `k` - Number of shortest paths to return
+///
+/// # Returns
+///
+/// Up to `k` `PathResult`s in non-decreasing order of distance. Fewer than
+/// `k` paths are returned if the graph does not contain that many distinct
+/// loopless paths between `start` and `end`.
+///
+/// # Example
+///
+/// ```
+/// use graph_algorithms::dijkstra::Graph;
+/// use graph_algorithms::yen::yen_k_shortest;
+/// use std::collections::HashMap;
+///
+/// let mut graph: Graph<&str, i32> = HashMap::new();
+/// graph.insert("A", vec![("B", 1), ("C", 2)]);
+/// graph.insert("B", vec![("D", 2)]);
+/// graph.insert("C", vec![("D", 2)]);
+/// graph.insert("D", vec![]);
+///
+/// let paths = yen_k_shortest(&graph, &"A", &"D", 2);
+/// assert_eq!(paths.len(), 2);
+/// assert_eq!(paths[0].distance, 3);
+/// ```
+pub fn yen_k_shortest<T, W>(graph: &Graph<T, W>, start: &T, end: &T, k: usize) -> Vec<PathResult<T, W>>
+where
+    T: Eq + Hash + Clone + std::fmt::Debug,
+    W: Weight,
+{
+    let mut found: Vec<PathResult<T, W>> = Vec::new();
+
+    if k == 0 {
+        return found;
+    }
+
+    let mut found_paths: HashSet<Vec<T>> = HashSet::new();
+    let mut candidates: BinaryHeap<PQItem<Vec<T>, W>> = BinaryHeap::new();
+
+    let shortest = match dijkstra_path(graph, start, end) {
+        Ok(path) => path,
+        Err(_) => return found,
+    };
+    found_paths.insert(shortest.path.clone());
+    found.push(shortest);
+
+    while found.len() < k {
+        let prev_path = found[found.len() - 1].path.clone();
+
+        for i in 0..prev_path.len().saturating_sub(1) {
+            let spur_node = &prev_path[i];
+            let root_path = &prev_path[..=i];
+
+            // Remove edges that would reproduce an already-found path
+            // sharing this root prefix.
+            let mut modified: Graph<T, W> = graph.clone();
+            for path in found.iter().map(|p| &p.path) {
+                if path.len() > i && path[..=i] == *root_path {
+                    let (u, v) = (&path[i], &path[i + 1]);
+                    if let Some(edges) = modified.get_mut(u) {
+                        edges.retain(|(node, _)| node != v);
+                    }
+                }
+            }
+
+            // Remove the root path's interior nodes (everything but the
+            // spur node itself) so the spur search cannot loop back onto it.
+            for node in &root_path[..i] {
+                modified.remove(node);
+                for edges in modified.values_mut() {
+                    edges.retain(|(n, _)| n != node);
+                }
+            }
+
+            if let Ok(spur_result) = dijkstra_path(&modified, spur_node, end) {
+                let mut total_path = root_path[..i].to_vec();
+                total_path.extend(spur_result.path.iter().cloned());
+
+                if found_paths.contains(&total_path) {
+                    continue;
+                }
+
+                let root_distance = path_distance(graph, root_path);
+                let distance = root_distance + spur_result.distance;
+                candidates.push(PQItem {
+                    node: total_path,
+                    distance,
+                });
+            }
+        }
+
+        if candidates.is_empty() {
+            break;
+        }
+
+        let mut next = None;
+        while let Some(candidate) = candidates.pop() {
+            if found_paths.insert(candidate.node.clone()) {
+                next = Some(candidate);
+                break;
+            }
+        }
+
+        match next {
+            Some(candidate) => found.push(PathResult {
+                distance: candidate.distance,
+                path: candidate.node,
+            }),
+            None => break,
+        }
+    }
+
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_k_shortest_paths() {
+        let mut graph: Graph<&str, i32> = HashMap::new();
+        graph.insert("A", vec![("B", 1), ("C", 2)]);
+        graph.insert("B", vec![("D", 2)]);
+        graph.insert("C", vec![("D", 2)]);
+        graph.insert("D", vec![]);
+
+        let paths = yen_k_shortest(&graph, &"A", &"D", 2);
+
+        assert_eq!(paths.len(), 2);
+        assert_eq!(paths[0].distance, 3);
+        assert_eq!(paths[0].path, vec!["A", "B", "D"]);
+        assert_eq!(paths[1].distance, 4);
+        assert_eq!(paths[1].path, vec!["A", "C", "D"]);
+    }
+
+    #[test]
+    fn test_fewer_than_k_paths_available() {
+        let mut graph: Graph<&str, i32> = HashMap::new();
+        graph.insert("A", vec![("B", 1)]);
+        graph.insert("B", vec![("C", 1)]);
+        graph.insert("C", vec![]);
+
+        let paths = yen_k_shortest(&graph, &"A", &"C", 5);
+
+        assert_eq!(paths.len(), 1);
+    }
+
+    #[test]
+    fn test_no_path() {
+        let mut graph: Graph<&str, i32> = HashMap::new();
+        graph.insert("A", vec![]);
+        graph.insert("B", vec![]);
+
+        let paths = yen_k_shortest(&graph, &"A", &"B", 3);
+
+        assert!(paths.is_empty());
+    }
+}